@@ -1,18 +1,208 @@
-use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::{Duration, Instant};
 
+use std::io::Write;
+
 use tauri::{
     menu::{Menu, MenuItem},
-    tray::{MouseButton, TrayIconBuilder, TrayIconEvent},
-    AppHandle, Manager, RunEvent, WindowEvent,
+    tray::{MouseButton, TrayIcon, TrayIconBuilder, TrayIconEvent},
+    AppHandle, Emitter, Manager, RunEvent, WindowEvent,
+};
+use tauri_plugin_shell::{
+    process::{CommandChild, CommandEvent},
+    ShellExt,
 };
-use tauri_plugin_shell::{process::CommandChild, ShellExt};
 
 struct ServerState {
     child: Option<CommandChild>,
 }
 
+const DEFAULT_PORT: u16 = 8000;
+const PORT_ENV_VAR: &str = "SLATE_PORT";
+const CONFIG_FILE_NAME: &str = "server.json";
+
+/// Port the sidecar is told to listen on. Persisted to `server.json` in the
+/// app config dir so the chosen port survives restarts, and overridable
+/// per-launch via the `SLATE_PORT` env var. Only the port is configurable;
+/// the health checks and port probes always target `127.0.0.1`.
+#[derive(Clone, Copy, serde::Serialize, serde::Deserialize)]
+struct ServerConfig {
+    port: u16,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self { port: DEFAULT_PORT }
+    }
+}
+
+impl ServerConfig {
+    fn load(app: &AppHandle) -> Self {
+        if let Some(port) = std::env::var(PORT_ENV_VAR)
+            .ok()
+            .and_then(|v| v.parse::<u16>().ok())
+        {
+            return Self { port };
+        }
+
+        app.path()
+            .app_config_dir()
+            .ok()
+            .map(|dir| dir.join(CONFIG_FILE_NAME))
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    fn save(&self, app: &AppHandle) {
+        let Ok(dir) = app.path().app_config_dir() else {
+            return;
+        };
+        let _ = std::fs::create_dir_all(&dir);
+        if let Ok(json) = serde_json::to_string_pretty(self) {
+            let _ = std::fs::write(dir.join(CONFIG_FILE_NAME), json);
+        }
+    }
+}
+
+/// Finds a free ephemeral port by letting the OS pick one, for when the
+/// preferred port is held by a process that can't be identified.
+fn find_free_port() -> Option<u16> {
+    std::net::TcpListener::bind("127.0.0.1:0")
+        .ok()
+        .and_then(|listener| listener.local_addr().ok())
+        .map(|addr| addr.port())
+}
+
+/// Identifies whichever process owns a listening TCP port, so a "port is
+/// occupied" error can name the actual offender instead of shrugging.
+struct PortOwner {
+    pid: u32,
+    name: String,
+}
+
+#[cfg(unix)]
+fn find_port_owner(port: u16) -> Option<PortOwner> {
+    let port_hex = format!("{:04X}", port);
+    for path in ["/proc/net/tcp", "/proc/net/tcp6"] {
+        let Ok(contents) = std::fs::read_to_string(path) else {
+            continue;
+        };
+        for line in contents.lines().skip(1) {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            // local_address is field 1 ("IP:PORT" in hex), inode is field 9.
+            let (Some(local), Some(inode)) = (fields.get(1), fields.get(9)) else {
+                continue;
+            };
+            let Some(local_port) = local.split(':').nth(1) else {
+                continue;
+            };
+            if !local_port.eq_ignore_ascii_case(&port_hex) || *inode == "0" {
+                continue;
+            }
+            if let Some(owner) = find_process_by_socket_inode(inode) {
+                return Some(owner);
+            }
+        }
+    }
+    None
+}
+
+/// Scans `/proc/<pid>/fd` for the socket inode found in `/proc/net/tcp`, the
+/// same trick `netstat`/`lsof` use to map a socket back to its owning pid.
+#[cfg(unix)]
+fn find_process_by_socket_inode(inode: &str) -> Option<PortOwner> {
+    let target = format!("socket:[{}]", inode);
+    for entry in std::fs::read_dir("/proc").ok()?.flatten() {
+        let Ok(pid) = entry.file_name().to_string_lossy().parse::<u32>() else {
+            continue;
+        };
+        let Ok(fds) = std::fs::read_dir(entry.path().join("fd")) else {
+            continue;
+        };
+        for fd in fds.flatten() {
+            let Ok(link) = std::fs::read_link(fd.path()) else {
+                continue;
+            };
+            if link.to_string_lossy() == target {
+                let name = std::fs::read_to_string(entry.path().join("comm"))
+                    .map(|s| s.trim().to_string())
+                    .unwrap_or_else(|_| "unknown".to_string());
+                return Some(PortOwner { pid, name });
+            }
+        }
+    }
+    None
+}
+
+#[cfg(windows)]
+fn find_port_owner(port: u16) -> Option<PortOwner> {
+    use windows::Win32::Foundation::NO_ERROR;
+    use windows::Win32::NetworkManagement::IpHelper::{
+        GetExtendedTcpTable, MIB_TCPTABLE_OWNER_PID, TCP_TABLE_OWNER_PID_ALL,
+    };
+
+    // dwLocalPort is stored in network byte order in the low 16 bits.
+    fn port_from_raw(raw: u32) -> u16 {
+        ((raw & 0xFF) << 8 | (raw >> 8) & 0xFF) as u16
+    }
+
+    unsafe {
+        let mut size = 0u32;
+        let _ = GetExtendedTcpTable(None, &mut size, false, 2 /* AF_INET */, TCP_TABLE_OWNER_PID_ALL, 0);
+        if size == 0 {
+            return None;
+        }
+
+        let mut buffer = vec![0u8; size as usize];
+        let status = GetExtendedTcpTable(
+            Some(buffer.as_mut_ptr() as *mut _),
+            &mut size,
+            false,
+            2, // AF_INET
+            TCP_TABLE_OWNER_PID_ALL,
+            0,
+        );
+        if status != NO_ERROR.0 {
+            return None;
+        }
+
+        let table = &*(buffer.as_ptr() as *const MIB_TCPTABLE_OWNER_PID);
+        let rows = std::slice::from_raw_parts(table.table.as_ptr(), table.dwNumEntries as usize);
+        rows.iter()
+            .find(|row| port_from_raw(row.dwLocalPort) == port)
+            .map(|row| PortOwner {
+                pid: row.dwOwningProcessId,
+                name: process_name_from_pid(row.dwOwningProcessId)
+                    .unwrap_or_else(|| "unknown".to_string()),
+            })
+    }
+}
+
+#[cfg(windows)]
+fn process_name_from_pid(pid: u32) -> Option<String> {
+    use windows::Win32::Foundation::CloseHandle;
+    use windows::Win32::System::ProcessStatus::GetModuleBaseNameW;
+    use windows::Win32::System::Threading::{
+        OpenProcess, PROCESS_QUERY_LIMITED_INFORMATION, PROCESS_VM_READ,
+    };
+
+    unsafe {
+        let handle =
+            OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION | PROCESS_VM_READ, false, pid).ok()?;
+        let mut name_buf = [0u16; 260];
+        let len = GetModuleBaseNameW(handle, None, &mut name_buf);
+        let _ = CloseHandle(handle);
+        if len == 0 {
+            return None;
+        }
+        Some(String::from_utf16_lossy(&name_buf[..len as usize]))
+    }
+}
+
 #[derive(serde::Deserialize)]
 struct HealthResponse {
     #[allow(dead_code)]
@@ -23,16 +213,57 @@ struct HealthResponse {
     pid: u32,
 }
 
-fn check_health() -> Option<HealthResponse> {
-    ureq::get("http://127.0.0.1:8000/health")
+fn check_health(port: u16) -> Option<HealthResponse> {
+    ureq::get(&format!("http://127.0.0.1:{}/health", port))
         .timeout(Duration::from_millis(500))
         .call()
         .ok()
         .and_then(|resp| resp.into_json::<HealthResponse>().ok())
 }
 
-fn kill_zombie(pid: u32) -> bool {
-    println!("[Lifecycle] Killing zombie server PID {}", pid);
+/// Terminates a single process by pid, cross-platform. When `graceful` is
+/// set, a termination signal is sent first and escalation to a hard kill
+/// only happens if the process is still alive after a short grace period.
+///
+/// This is NOT a process-tree kill on Unix: it signals `pid` itself, not a
+/// process group, so any children the sidecar spawns are left running. The
+/// sidecar is spawned through `tauri_plugin_shell`, whose `Command` doesn't
+/// expose a way to make the child its own process-group leader (no
+/// `setpgid`/`setsid`/`pre_exec` hook), so there is no PGID to target safely.
+/// On Windows, `taskkill /T` still reaps the whole tree, so behavior is
+/// asymmetric across platforms until the shell plugin exposes that hook.
+#[cfg(unix)]
+fn terminate_process(pid: u32, graceful: bool) -> bool {
+    use nix::sys::signal::{kill, Signal};
+    use nix::unistd::Pid;
+
+    let target = Pid::from_raw(pid as i32);
+
+    if !graceful {
+        return kill(target, Signal::SIGKILL).is_ok();
+    }
+
+    if kill(target, Signal::SIGTERM).is_err() {
+        return false;
+    }
+
+    let deadline = Instant::now() + Duration::from_millis(2000);
+    while Instant::now() < deadline {
+        if kill(target, None).is_err() {
+            // ESRCH: the process is gone, it exited on its own.
+            return true;
+        }
+        thread::sleep(Duration::from_millis(100));
+    }
+
+    kill(target, Signal::SIGKILL).is_ok()
+}
+
+#[cfg(windows)]
+fn terminate_process(pid: u32, _graceful: bool) -> bool {
+    // Windows has no SIGTERM equivalent for arbitrary processes, so both
+    // paths collapse to the same forceful tree-kill. Unlike the Unix path,
+    // `/T` does reap descendants.
     std::process::Command::new("taskkill")
         .args(["/T", "/F", "/PID", &pid.to_string()])
         .status()
@@ -40,10 +271,16 @@ fn kill_zombie(pid: u32) -> bool {
         .unwrap_or(false)
 }
 
-fn wait_for_port_free(timeout: Duration) -> bool {
+fn kill_zombie(pid: u32) -> bool {
+    println!("[Lifecycle] Killing zombie server PID {}", pid);
+    // See terminate_process: on Unix this only reaps `pid`, not its children.
+    terminate_process(pid, false)
+}
+
+fn wait_for_port_free(port: u16, timeout: Duration) -> bool {
     let start = Instant::now();
     while start.elapsed() < timeout {
-        if check_health().is_none() {
+        if check_health(port).is_none() {
             return true;
         }
         thread::sleep(Duration::from_millis(200));
@@ -51,10 +288,10 @@ fn wait_for_port_free(timeout: Duration) -> bool {
     false
 }
 
-fn wait_for_server_ready(timeout: Duration) -> bool {
+fn wait_for_server_ready(port: u16, timeout: Duration) -> bool {
     let start = Instant::now();
     while start.elapsed() < timeout {
-        if check_health().is_some() {
+        if check_health(port).is_some() {
             return true;
         }
         thread::sleep(Duration::from_millis(200));
@@ -62,27 +299,336 @@ fn wait_for_server_ready(timeout: Duration) -> bool {
     false
 }
 
-fn spawn_server(app: &AppHandle) -> Result<CommandChild, String> {
+/// Millisecond-precision wall clock timestamp for log lines, e.g. `12:03:41.205`.
+fn log_timestamp() -> String {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default();
+    let secs_today = now.as_secs() % 86_400;
+    format!(
+        "{:02}:{:02}:{:02}.{:03}",
+        secs_today / 3600,
+        (secs_today % 3600) / 60,
+        secs_today % 60,
+        now.subsec_millis()
+    )
+}
+
+fn spawn_server(app: &AppHandle, port: u16) -> Result<CommandChild, String> {
     let sidecar = app
         .shell()
         .sidecar("slate-server")
         .map_err(|e| format!("Failed to create sidecar command: {}", e))?
         .env("SLATE_OWNER", "tauri")
-        .env("SLATE_ENV", "prod");
+        .env("SLATE_ENV", "prod")
+        .env("SLATE_PORT", port.to_string());
 
-    let (_, child) = sidecar
+    let (mut rx, child) = sidecar
         .spawn()
         .map_err(|e| format!("Failed to spawn sidecar: {}", e))?;
+    let child_pid = child.pid();
+
+    let log_path = app
+        .path()
+        .app_log_dir()
+        .map_err(|e| format!("Failed to resolve log dir: {}", e))?
+        .join("slate-server.log");
+    if let Some(dir) = log_path.parent() {
+        let _ = std::fs::create_dir_all(dir);
+    }
+
+    let app_handle = app.clone();
+    let restart_tx = app.state::<Sender<u32>>().inner().clone();
+    tauri::async_runtime::spawn(async move {
+        let mut log_file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&log_path)
+            .map_err(|e| println!("[Lifecycle] Failed to open server log {:?}: {}", log_path, e))
+            .ok();
+
+        while let Some(event) = rx.recv().await {
+            match event {
+                CommandEvent::Stdout(bytes) | CommandEvent::Stderr(bytes) => {
+                    let line = String::from_utf8_lossy(&bytes).trim_end().to_string();
+                    if let Some(file) = log_file.as_mut() {
+                        let _ = writeln!(file, "[{}] {}", log_timestamp(), line);
+                    }
+                    let _ = app_handle.emit("server-log", &line);
+                }
+                CommandEvent::Terminated(payload) => {
+                    println!("[Lifecycle] Sidecar terminated with code {:?}", payload.code);
+                    let _ = app_handle.emit("server-exited", payload.code);
+                    // Wake the supervisor immediately instead of making it
+                    // wait out a full health-poll window to notice. The pid
+                    // lets the supervisor tell a genuine crash of the child
+                    // it's tracking apart from a stale signal left over from
+                    // a server that stop_server/restart_server already
+                    // replaced or tore down intentionally.
+                    let _ = restart_tx.send(child_pid);
+                    break;
+                }
+                _ => {}
+            }
+        }
+    });
 
     Ok(child)
 }
 
-fn kill_sidecar_tree(child: CommandChild) {
+/// Stops the sidecar. Despite the name, this does not reap a process tree
+/// on Unix (see `terminate_process`) — any children the sidecar spawned are
+/// left running. Kept as-is pending a shell-plugin hook to establish a
+/// process group; only the Windows path actually kills descendants.
+fn kill_sidecar_tree(child: CommandChild, graceful: bool) {
     let pid = child.pid();
-    println!("[Lifecycle] Force killing sidecar tree for PID {}", pid);
-    let _ = std::process::Command::new("taskkill")
-        .args(["/T", "/F", "/PID", &pid.to_string()])
-        .status();
+    println!(
+        "[Lifecycle] {} sidecar tree for PID {}",
+        if graceful { "Gracefully stopping" } else { "Force killing" },
+        pid
+    );
+    terminate_process(pid, graceful);
+}
+
+#[derive(Clone, serde::Serialize)]
+struct ServerStatusPayload {
+    state: &'static str,
+    pid: Option<u32>,
+}
+
+/// Builds a flat-colored fallback tray icon so the degraded state has a
+/// visually distinct badge without shipping a second icon asset.
+fn degraded_tray_icon() -> tauri::image::Image<'static> {
+    const SIZE: u32 = 32;
+    let mut rgba = Vec::with_capacity((SIZE * SIZE * 4) as usize);
+    for _ in 0..(SIZE * SIZE) {
+        rgba.extend_from_slice(&[217, 83, 79, 255]);
+    }
+    tauri::image::Image::new_owned(rgba, SIZE, SIZE)
+}
+
+fn update_tray_health(app: &AppHandle, healthy: bool) {
+    let Some(tray) = app.try_state::<TrayIcon>() else {
+        return;
+    };
+    let icon = if healthy {
+        app.default_window_icon().cloned()
+    } else {
+        Some(degraded_tray_icon())
+    };
+    let _ = tray.set_icon(icon);
+    let _ = tray.set_tooltip(Some(if healthy {
+        "Slate — server running"
+    } else {
+        "Slate — server unavailable"
+    }));
+}
+
+/// Emits a `server-status` event for the frontend to react to and swaps the
+/// tray icon/tooltip to match.
+fn emit_status(app: &AppHandle, state: &'static str, pid: Option<u32>) {
+    let _ = app.emit("server-status", ServerStatusPayload { state, pid });
+    update_tray_health(app, state == "ready");
+}
+
+/// Starts the sidecar if it isn't already running, emitting status
+/// transitions as it goes.
+#[tauri::command]
+fn start_server(app: AppHandle) -> Result<(), String> {
+    {
+        let state = app.state::<Mutex<ServerState>>();
+        let guard = state.lock().map_err(|_| "Server state poisoned".to_string())?;
+        if guard.child.is_some() {
+            return Err("Server is already running.".into());
+        }
+    }
+
+    let port = {
+        let config = app.state::<Mutex<ServerConfig>>();
+        config.lock().map(|c| c.port).unwrap_or(DEFAULT_PORT)
+    };
+
+    emit_status(&app, "starting", None);
+    let child = spawn_server(&app, port)?;
+    let pid = child.pid();
+    {
+        let state = app.state::<Mutex<ServerState>>();
+        if let Ok(mut guard) = state.lock() {
+            guard.child = Some(child);
+        }
+    }
+
+    if wait_for_server_ready(port, Duration::from_secs(10)) {
+        emit_status(&app, "ready", Some(pid));
+        Ok(())
+    } else {
+        emit_status(&app, "failed", Some(pid));
+        Err("Server failed to become healthy within timeout.".into())
+    }
+}
+
+/// Stops the running sidecar, if any.
+#[tauri::command]
+fn stop_server(app: AppHandle) -> Result<(), String> {
+    let child = {
+        let state = app.state::<Mutex<ServerState>>();
+        state
+            .lock()
+            .map_err(|_| "Server state poisoned".to_string())?
+            .child
+            .take()
+    };
+
+    match child {
+        Some(child) => {
+            kill_sidecar_tree(child, true);
+            emit_status(&app, "stopped", None);
+            Ok(())
+        }
+        None => Err("Server is not running.".into()),
+    }
+}
+
+/// Stops then starts the sidecar again.
+#[tauri::command]
+fn restart_server(app: AppHandle) -> Result<(), String> {
+    let _ = stop_server(app.clone());
+    start_server(app)
+}
+
+const HEALTH_POLL_INTERVAL: Duration = Duration::from_secs(2);
+const FAILURE_THRESHOLD: u32 = 3;
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+const MAX_RAPID_RESTARTS: u32 = 5;
+const RESTART_WINDOW: Duration = Duration::from_secs(60);
+
+/// Watches the sidecar's health and respawns it if it disappears or stops
+/// responding, with exponential backoff so a server that crash-loops doesn't
+/// hammer the CPU. Backs off entirely once `shutdown` is set so it doesn't
+/// fight the tray "quit" handler or `RunEvent::Exit`.
+fn spawn_supervisor(app: AppHandle, shutdown: Arc<AtomicBool>, restart_rx: Receiver<u32>) {
+    // Runs on its own OS thread rather than `tauri::async_runtime::spawn`: the
+    // backoff sleeps here can run up to `MAX_BACKOFF` (30s), and `check_health`
+    // is a blocking `ureq` call. Parking a tokio worker for that long would
+    // stall the other async tasks sharing the runtime (e.g. the sidecar
+    // stdout/stderr forwarder).
+    thread::spawn(move || {
+        let mut consecutive_failures = 0u32;
+        let mut backoff = INITIAL_BACKOFF;
+        let mut rapid_restarts = 0u32;
+        let mut window_start = Instant::now();
+
+        loop {
+            // Blocks until either the sidecar reports CommandEvent::Terminated
+            // (carrying the terminated child's pid) or the poll interval
+            // elapses, whichever comes first.
+            let terminated_pid = restart_rx.recv_timeout(HEALTH_POLL_INTERVAL).ok();
+
+            if shutdown.load(Ordering::SeqCst) {
+                break;
+            }
+
+            let port = {
+                let state = app.state::<Mutex<ServerConfig>>();
+                state.lock().map(|c| c.port).unwrap_or(DEFAULT_PORT)
+            };
+
+            // stop_server/restart_server tear down (or replace) the tracked
+            // child before this signal arrives, so a pid that no longer
+            // matches the child we're currently tracking is a stale signal
+            // from a server we already know is gone, not a crash to react
+            // to. Drop it without touching the failure/backoff accounting.
+            let live_pid = {
+                let state = app.state::<Mutex<ServerState>>();
+                state.lock().ok().and_then(|guard| guard.child.as_ref().map(|c| c.pid()))
+            };
+            let terminated = match terminated_pid {
+                Some(pid) if Some(pid) == live_pid => true,
+                Some(_) => continue,
+                None => false,
+            };
+
+            if !terminated && check_health(port).is_some() {
+                consecutive_failures = 0;
+                backoff = INITIAL_BACKOFF;
+                if window_start.elapsed() > RESTART_WINDOW {
+                    rapid_restarts = 0;
+                    window_start = Instant::now();
+                }
+                continue;
+            }
+
+            if terminated {
+                println!("[Supervisor] Sidecar reported Terminated, restarting...");
+                consecutive_failures = FAILURE_THRESHOLD;
+            } else {
+                consecutive_failures += 1;
+                if consecutive_failures < FAILURE_THRESHOLD {
+                    continue;
+                }
+            }
+
+            if rapid_restarts >= MAX_RAPID_RESTARTS {
+                eprintln!(
+                    "[Supervisor] {} restarts within {:?}, backing off for {:?} before trying again",
+                    rapid_restarts, window_start.elapsed(), MAX_BACKOFF
+                );
+                thread::sleep(MAX_BACKOFF);
+                rapid_restarts = 0;
+                window_start = Instant::now();
+                consecutive_failures = 0;
+                continue;
+            }
+
+            println!(
+                "[Supervisor] Server unresponsive after {} checks, restarting in {:?}...",
+                consecutive_failures, backoff
+            );
+            thread::sleep(backoff);
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+            rapid_restarts += 1;
+            consecutive_failures = 0;
+
+            if shutdown.load(Ordering::SeqCst) {
+                break;
+            }
+
+            emit_status(&app, "starting", None);
+
+            let old_child = {
+                let state = app.state::<Mutex<ServerState>>();
+                state.lock().ok().and_then(|mut guard| guard.child.take())
+            };
+            if let Some(old_child) = old_child {
+                kill_sidecar_tree(old_child, false);
+            }
+
+            match spawn_server(&app, port) {
+                Ok(child) => {
+                    let pid = child.pid();
+                    {
+                        let state = app.state::<Mutex<ServerState>>();
+                        if let Ok(mut guard) = state.lock() {
+                            guard.child = Some(child);
+                        }
+                    }
+                    if wait_for_server_ready(port, Duration::from_secs(10)) {
+                        println!("[Supervisor] Respawned sidecar on port {}", port);
+                        emit_status(&app, "ready", Some(pid));
+                    } else {
+                        emit_status(&app, "failed", Some(pid));
+                    }
+                }
+                Err(e) => {
+                    eprintln!("[Supervisor] Failed to respawn sidecar: {}", e);
+                    emit_status(&app, "failed", None);
+                }
+            }
+        }
+
+        println!("[Supervisor] Shutting down");
+    });
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
@@ -92,25 +638,36 @@ pub fn run() {
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_shell::init())
         .manage(Mutex::new(ServerState { child: None }))
+        .manage(Arc::new(AtomicBool::new(false)))
+        .invoke_handler(tauri::generate_handler![start_server, stop_server, restart_server])
         .setup(|app| {
             let quit_i = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
             let show_i = MenuItem::with_id(app, "show", "Open Slate Editor", true, None::<&str>)?;
-            let menu = Menu::with_items(app, &[&show_i, &quit_i])?;
+            let restart_i = MenuItem::with_id(app, "restart", "Restart Server", true, None::<&str>)?;
+            let stop_i = MenuItem::with_id(app, "stop", "Stop Server", true, None::<&str>)?;
+            let menu = Menu::with_items(app, &[&show_i, &restart_i, &stop_i, &quit_i])?;
+
+            // Lets the sidecar's CommandEvent::Terminated reach the
+            // supervisor immediately instead of it waiting out a poll cycle.
+            let (restart_tx, restart_rx) = mpsc::channel::<u32>();
+            app.manage(restart_tx);
 
             let app_handle = app.handle().clone();
-            let _tray = TrayIconBuilder::new()
+            let tray = TrayIconBuilder::new()
                 .icon(app.default_window_icon().unwrap().clone())
                 .menu(&menu)
                 .show_menu_on_left_click(false)
                 .on_menu_event(move |_tray, event| match event.id.as_ref() {
                     "quit" => {
                         println!("[App] Quit requested from tray");
+                        app_handle.state::<Arc<AtomicBool>>().store(true, Ordering::SeqCst);
                         let state = app_handle.state::<Mutex<ServerState>>();
                         if let Ok(mut guard) = state.lock() {
                             if let Some(child) = guard.child.take() {
-                                kill_sidecar_tree(child);
+                                kill_sidecar_tree(child, true);
                             }
                         }
+                        emit_status(&app_handle, "stopped", None);
                         app_handle.exit(0);
                     }
                     "show" => {
@@ -119,6 +676,22 @@ pub fn run() {
                             let _ = window.set_focus();
                         }
                     }
+                    "restart" => {
+                        let app_handle = app_handle.clone();
+                        thread::spawn(move || {
+                            if let Err(e) = restart_server(app_handle) {
+                                eprintln!("[Lifecycle] Restart from tray failed: {}", e);
+                            }
+                        });
+                    }
+                    "stop" => {
+                        let app_handle = app_handle.clone();
+                        thread::spawn(move || {
+                            if let Err(e) = stop_server(app_handle) {
+                                eprintln!("[Lifecycle] Stop from tray failed: {}", e);
+                            }
+                        });
+                    }
                     _ => {}
                 })
                 .on_tray_icon_event(|tray, event| match event {
@@ -135,24 +708,59 @@ pub fn run() {
                     _ => {}
                 })
                 .build(app)?;
+            app.manage(tray);
+
+            let mut config = ServerConfig::load(app.handle());
 
-            match check_health() {
+            match check_health(config.port) {
                 None => {
-                    println!("[Lifecycle] Port 8000 unresponsive or invalid fingerprint. Checking for legacy processes...");
+                    println!("[Lifecycle] Port {} unresponsive or invalid fingerprint. Checking for legacy processes...", config.port);
                     if std::net::TcpStream::connect_timeout(
-                        &"127.0.0.1:8000".parse().unwrap(),
+                        &format!("127.0.0.1:{}", config.port).parse().unwrap(),
                         Duration::from_millis(100),
                     )
                     .is_ok()
                     {
-                        println!("[Lifecycle] Port 8000 occupied by unknown/legacy process. Please close any old slate-server instances.");
-                        return Err("Port 8000 is occupied. Close old server instances first.".into());
+                        match find_port_owner(config.port) {
+                            Some(owner) if owner.name.to_lowercase().contains("slate-server") => {
+                                println!(
+                                    "[Lifecycle] Port {} held by a stale slate-server process ({}, PID {}), killing it...",
+                                    config.port, owner.name, owner.pid
+                                );
+                                if !kill_zombie(owner.pid) {
+                                    return Err("Failed to terminate stale server.".into());
+                                }
+                                if !wait_for_port_free(config.port, Duration::from_secs(2)) {
+                                    return Err(format!("Could not free port {} after killing stale server.", config.port).into());
+                                }
+                                println!("[Lifecycle] Port freed, spawning new sidecar...");
+                            }
+                            Some(owner) => {
+                                return Err(format!(
+                                    "Port {} is occupied by {} (PID {}). Close it and relaunch Slate.",
+                                    config.port, owner.name, owner.pid
+                                )
+                                .into());
+                            }
+                            None => {
+                                println!(
+                                    "[Lifecycle] Port {} occupied by an unidentifiable process. Picking a free port instead...",
+                                    config.port
+                                );
+                                config.port = find_free_port().ok_or_else(|| {
+                                    "Could not find a free port for the server.".to_string()
+                                })?;
+                                config.save(app.handle());
+                                println!("[Lifecycle] Selected free port {}", config.port);
+                            }
+                        }
+                    } else {
+                        println!("[Lifecycle] Port {} free, spawning sidecar...", config.port);
                     }
-                    println!("[Lifecycle] Port 8000 free, spawning sidecar...");
                 }
                 Some(health) => {
                     if health.app != "slate-server" {
-                        return Err("Port 8000 is in use by another application.".into());
+                        return Err(format!("Port {} is in use by another application.", config.port).into());
                     }
                     if health.owner != "tauri" || health.env != "prod" {
                         return Err(format!(
@@ -168,25 +776,34 @@ pub fn run() {
                     if !kill_zombie(health.pid) {
                         return Err("Failed to terminate zombie server.".into());
                     }
-                    if !wait_for_port_free(Duration::from_secs(2)) {
-                        return Err("Could not free port 8000 after killing zombie.".into());
+                    if !wait_for_port_free(config.port, Duration::from_secs(2)) {
+                        return Err(format!("Could not free port {} after killing zombie.", config.port).into());
                     }
                     println!("[Lifecycle] Port freed, spawning new sidecar...");
                 }
             }
 
-            let child = spawn_server(app.handle())?;
+            app.manage(Mutex::new(config));
+
+            emit_status(app.handle(), "starting", None);
+            let child = spawn_server(app.handle(), config.port)?;
+            let pid = child.pid();
             let state = app.state::<Mutex<ServerState>>();
             if let Ok(mut guard) = state.lock() {
                 guard.child = Some(child);
             }
 
-            if wait_for_server_ready(Duration::from_secs(10)) {
+            if wait_for_server_ready(config.port, Duration::from_secs(10)) {
                 println!("[Lifecycle] Server ready");
+                emit_status(app.handle(), "ready", Some(pid));
             } else {
                 eprintln!("[Lifecycle] Server failed to start within timeout");
+                emit_status(app.handle(), "failed", Some(pid));
             }
 
+            let shutdown = app.state::<Arc<AtomicBool>>().inner().clone();
+            spawn_supervisor(app.handle().clone(), shutdown, restart_rx);
+
             Ok(())
         })
         .build(tauri::generate_context!())
@@ -206,12 +823,14 @@ pub fn run() {
             }
             RunEvent::Exit => {
                 println!("[App] App exiting, killing sidecar...");
+                app.state::<Arc<AtomicBool>>().store(true, Ordering::SeqCst);
                 let state = app.state::<Mutex<ServerState>>();
                 if let Ok(mut guard) = state.lock() {
                     if let Some(child) = guard.child.take() {
-                        kill_sidecar_tree(child);
+                        kill_sidecar_tree(child, true);
                     }
                 };
+                emit_status(app, "stopped", None);
             }
             _ => {}
         });